@@ -0,0 +1,49 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A variety of rand-compatible xoshiro/xoroshiro RNGs.
+
+#![deny(missing_docs)]
+#![no_std]
+
+pub use rand_core;
+
+#[macro_use]
+mod macros;
+
+mod seed512;
+mod xoroshiro128plus;
+mod xoroshiro128plusplus;
+mod xoroshiro128starstar;
+mod xoroshiro64star;
+mod xoroshiro64starstar;
+mod xoshiro128plus;
+mod xoshiro128plusplus;
+mod xoshiro128starstar;
+mod xoshiro256plus;
+mod xoshiro256plusplus;
+mod xoshiro256starstar;
+mod xoshiro512plus;
+mod xoshiro512plusplus;
+mod xoshiro512starstar;
+
+pub use crate::seed512::Seed512;
+pub use crate::xoroshiro128plus::Xoroshiro128Plus;
+pub use crate::xoroshiro128plusplus::Xoroshiro128PlusPlus;
+pub use crate::xoroshiro128starstar::Xoroshiro128StarStar;
+pub use crate::xoroshiro64star::Xoroshiro64Star;
+pub use crate::xoroshiro64starstar::Xoroshiro64StarStar;
+pub use crate::xoshiro128plus::Xoshiro128Plus;
+pub use crate::xoshiro128plusplus::Xoshiro128PlusPlus;
+pub use crate::xoshiro128starstar::Xoshiro128StarStar;
+pub use crate::xoshiro256plus::Xoshiro256Plus;
+pub use crate::xoshiro256plusplus::Xoshiro256PlusPlus;
+pub use crate::xoshiro256starstar::Xoshiro256StarStar;
+pub use crate::xoshiro512plus::Xoshiro512Plus;
+pub use crate::xoshiro512plusplus::Xoshiro512PlusPlus;
+pub use crate::xoshiro512starstar::Xoshiro512StarStar;
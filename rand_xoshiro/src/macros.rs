@@ -0,0 +1,267 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/// Return early with a `SplitMix64`-derived seed if `seed` is all zero.
+///
+/// The xoshiro/xoroshiro generators are only required to reject the
+/// all-zero state; everything else is a valid seed.
+macro_rules! deal_with_zero_seed {
+    ($seed:expr, $Self:ident, $seed_len:expr) => {
+        if $seed == [0; $seed_len] {
+            return $Self::seed_from_u64(0);
+        }
+    };
+}
+
+/// Fill `Self::Seed` from a `SplitMix64` stream started at `seed`, as
+/// recommended by the xoshiro/xoroshiro authors for seeding from a `u64`.
+macro_rules! from_splitmix {
+    ($seed:expr) => {{
+        let mut x = $seed;
+        let mut seed = Self::Seed::default();
+        for chunk in seed.as_mut().chunks_mut(8) {
+            x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = x;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^= z >> 31;
+            chunk.copy_from_slice(&z.to_le_bytes()[..chunk.len()]);
+        }
+        Self::from_seed(seed)
+    }};
+}
+
+/// Advance a 4-word xoshiro128 (`u32`) state.
+macro_rules! impl_xoshiro_u32 {
+    ($self:expr) => {{
+        let t = $self.s[1] << 9;
+
+        $self.s[2] ^= $self.s[0];
+        $self.s[3] ^= $self.s[1];
+        $self.s[1] ^= $self.s[2];
+        $self.s[0] ^= $self.s[3];
+        $self.s[2] ^= t;
+
+        $self.s[3] = $self.s[3].rotate_left(11);
+    }};
+}
+
+/// The `**` scrambler, shared by the xoshiro128/256/512 and xoroshiro128
+/// `**` variants regardless of word width. xoroshiro64** uses its own
+/// scrambler instead.
+macro_rules! starstar_u64 {
+    ($s1:expr) => {{
+        ($s1.wrapping_mul(5)).rotate_left(7).wrapping_mul(9)
+    }};
+}
+
+/// The `++` scrambler for the 128-bit-state xoshiro generators:
+/// `rotl(s0 + s3, 7) + s0`.
+macro_rules! plusplus_u32 {
+    ($self:expr) => {{
+        ($self.s[0].wrapping_add($self.s[3]))
+            .rotate_left(7)
+            .wrapping_add($self.s[0])
+    }};
+}
+
+/// Advance a 4-word xoshiro256 (`u64`) state.
+macro_rules! impl_xoshiro_u64 {
+    ($self:expr) => {{
+        let t = $self.s[1] << 17;
+
+        $self.s[2] ^= $self.s[0];
+        $self.s[3] ^= $self.s[1];
+        $self.s[1] ^= $self.s[2];
+        $self.s[0] ^= $self.s[3];
+        $self.s[2] ^= t;
+
+        $self.s[3] = $self.s[3].rotate_left(45);
+    }};
+}
+
+/// Advance an 8-word xoshiro512 (`u64`) state.
+macro_rules! impl_xoshiro512_u64 {
+    ($self:expr) => {{
+        let t = $self.s[1] << 11;
+
+        $self.s[2] ^= $self.s[0];
+        $self.s[5] ^= $self.s[1];
+        $self.s[1] ^= $self.s[2];
+        $self.s[7] ^= $self.s[3];
+        $self.s[3] ^= $self.s[4];
+        $self.s[4] ^= $self.s[5];
+        $self.s[0] ^= $self.s[6];
+        $self.s[6] ^= $self.s[7];
+        $self.s[6] ^= t;
+
+        $self.s[7] = $self.s[7].rotate_left(21);
+    }};
+}
+
+/// Advance a 2-word xoroshiro128 (`u64`) state for the `+`/`**` variants.
+macro_rules! impl_xoroshiro_u64 {
+    ($self:expr) => {{
+        let s0 = $self.s0;
+        let mut s1 = $self.s1;
+        s1 ^= s0;
+        $self.s0 = s0.rotate_left(24) ^ s1 ^ (s1 << 16);
+        $self.s1 = s1.rotate_left(37);
+    }};
+}
+
+/// Advance a 2-word xoroshiro128 (`u64`) state for the `++` variant.
+macro_rules! impl_xoroshiro_u64_plusplus {
+    ($self:expr) => {{
+        let s0 = $self.s0;
+        let mut s1 = $self.s1;
+        s1 ^= s0;
+        $self.s0 = s0.rotate_left(49) ^ s1 ^ (s1 << 21);
+        $self.s1 = s1.rotate_left(28);
+    }};
+}
+
+/// Advance a 2-word xoroshiro64 (`u32`) state.
+macro_rules! impl_xoroshiro_u32 {
+    ($self:expr) => {{
+        let s0 = $self.s[0];
+        let mut s1 = $self.s[1];
+        s1 ^= s0;
+        $self.s[0] = s0.rotate_left(26) ^ s1 ^ (s1 << 9);
+        $self.s[1] = s1.rotate_left(13);
+    }};
+}
+
+/// Jump a 2-word xoroshiro128 (`u64`) generator forward by iterating the
+/// jump polynomial, advancing one step per bit via the generator's own
+/// `RngCore` implementation.
+macro_rules! impl_jump_xoroshiro128 {
+    ($self:expr, $jump:expr) => {{
+        let mut s0 = 0u64;
+        let mut s1 = 0u64;
+        let jump: [u64; 2] = $jump;
+        for &j in jump.iter() {
+            for b in 0..64 {
+                if (j >> b) & 1 != 0 {
+                    s0 ^= $self.s0;
+                    s1 ^= $self.s1;
+                }
+                $self.next_u64();
+            }
+        }
+        $self.s0 = s0;
+        $self.s1 = s1;
+    }};
+}
+
+/// Jump a 4-word xoshiro128 (`u32`), 4-word xoshiro256 (`u64`), or 8-word
+/// xoshiro512 (`u64`) generator forward by iterating the jump polynomial,
+/// advancing one step per bit via the generator's own `RngCore`
+/// implementation.
+macro_rules! impl_jump {
+    (u32, $self:expr, $jump:expr) => {{
+        let mut s = [0u32; 4];
+        let jump: [u32; 4] = $jump;
+        for &j in jump.iter() {
+            for b in 0..32 {
+                if (j >> b) & 1 != 0 {
+                    for i in 0..4 {
+                        s[i] ^= $self.s[i];
+                    }
+                }
+                $self.next_u32();
+            }
+        }
+        $self.s = s;
+    }};
+    (u64, $self:expr, $jump:expr) => {{
+        let mut s = [0u64; 4];
+        let jump: [u64; 4] = $jump;
+        for &j in jump.iter() {
+            for b in 0..64 {
+                if (j >> b) & 1 != 0 {
+                    for i in 0..4 {
+                        s[i] ^= $self.s[i];
+                    }
+                }
+                $self.next_u64();
+            }
+        }
+        $self.s = s;
+    }};
+    (u64, $self:expr, $jump:expr, 8) => {{
+        let mut s = [0u64; 8];
+        let jump: [u64; 8] = $jump;
+        for &j in jump.iter() {
+            for b in 0..64 {
+                if (j >> b) & 1 != 0 {
+                    for i in 0..8 {
+                        s[i] ^= $self.s[i];
+                    }
+                }
+                $self.next_u64();
+            }
+        }
+        $self.s = s;
+    }};
+}
+
+/// Add `split_streams`/`stream_iter` (and their `long_jump`-spaced
+/// counterparts) to a generator that already provides `jump()` and
+/// `long_jump()`. Meant to be invoked inside the type's inherent `impl`
+/// block, alongside those two methods.
+macro_rules! impl_split_streams {
+    ($Self:ident) => {
+        /// Return an iterator over `n` non-overlapping subsequences of
+        /// this generator, each one `jump()` apart from the last.
+        ///
+        /// This is a convenience wrapper around [`stream_iter`], for
+        /// when the number of streams needed is known up front.
+        ///
+        /// [`stream_iter`]: #method.stream_iter
+        pub fn split_streams(self, n: usize) -> impl Iterator<Item = $Self> {
+            self.stream_iter().take(n)
+        }
+
+        /// Return a lazy, unbounded iterator of non-overlapping
+        /// subsequences of this generator, each one `jump()` apart from
+        /// the last.
+        ///
+        /// The first item is `self` unmodified; every subsequent item is
+        /// a clone of the previous one, advanced by `jump()`. The
+        /// original generator passed in is not mutated beyond what is
+        /// yielded.
+        pub fn stream_iter(self) -> impl Iterator<Item = $Self> {
+            ::core::iter::successors(Some(self), |rng| {
+                let mut rng = rng.clone();
+                rng.jump();
+                Some(rng)
+            })
+        }
+
+        /// As [`split_streams`], but the returned streams are spaced by
+        /// `long_jump()` instead of `jump()`.
+        ///
+        /// [`split_streams`]: #method.split_streams
+        pub fn split_streams_long(self, n: usize) -> impl Iterator<Item = $Self> {
+            self.stream_iter_long().take(n)
+        }
+
+        /// As [`stream_iter`], but the returned streams are spaced by
+        /// `long_jump()` instead of `jump()`.
+        ///
+        /// [`stream_iter`]: #method.stream_iter
+        pub fn stream_iter_long(self) -> impl Iterator<Item = $Self> {
+            ::core::iter::successors(Some(self), |rng| {
+                let mut rng = rng.clone();
+                rng.long_jump();
+                Some(rng)
+            })
+        }
+    };
+}
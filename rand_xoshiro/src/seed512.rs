@@ -0,0 +1,33 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/// A 64-byte seed for the xoshiro512 generators.
+///
+/// `[u8; 64]` has no `Default` impl, which `SeedableRng::Seed` requires, so
+/// this thin wrapper provides `Default`, `AsRef<[u8]>` and `AsMut<[u8]>` by
+/// hand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Seed512(pub [u8; 64]);
+
+impl Default for Seed512 {
+    fn default() -> Seed512 {
+        Seed512([0; 64])
+    }
+}
+
+impl AsRef<[u8]> for Seed512 {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for Seed512 {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
@@ -0,0 +1,164 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use rand_core::impls::fill_bytes_via_next;
+use rand_core::le::read_u64_into;
+use rand_core::{RngCore, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A xoroshiro128+ random number generator.
+///
+/// The xoroshiro128+ algorithm is not suitable for cryptographic purposes,
+/// but is very fast and has good statistical properties, besides a low
+/// linear complexity in the lowest bits.
+///
+/// The algorithm used here is translated from [the `xoroshiro128plus.c`
+/// reference source code](http://xoshiro.di.unimi.it/xoroshiro128plus.c) by
+/// David Blackman and Sebastiano Vigna.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Xoroshiro128Plus {
+    s0: u64,
+    s1: u64,
+}
+
+impl Xoroshiro128Plus {
+    /// Jump forward, equivalently to 2^64 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^64 non-overlapping subsequences for
+    /// parallel computations.
+    ///
+    /// ```
+    /// use rand_xoshiro::rand_core::SeedableRng;
+    /// use rand_xoshiro::Xoroshiro128Plus;
+    ///
+    /// let rng1 = Xoroshiro128Plus::seed_from_u64(0);
+    /// let mut rng2 = rng1.clone();
+    /// rng2.jump();
+    /// let mut rng3 = rng2.clone();
+    /// rng3.jump();
+    /// ```
+    pub fn jump(&mut self) {
+        impl_jump_xoroshiro128!(self, [0xdf90_0294_d8f5_54a5, 0x1708_65df_4b32_01fc]);
+    }
+
+    /// Jump forward, equivalently to 2^96 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^32 starting points, from each of which
+    /// `jump()` will generate 2^32 non-overlapping subsequences for parallel
+    /// distributed computations.
+    pub fn long_jump(&mut self) {
+        impl_jump_xoroshiro128!(self, [0xd2a9_8b26_625e_ee7b, 0xdddf_9b10_90aa_7ac1]);
+    }
+
+    impl_split_streams!(Xoroshiro128Plus);
+}
+
+impl SeedableRng for Xoroshiro128Plus {
+    type Seed = [u8; 16];
+
+    /// Create a new `Xoroshiro128Plus`.  If `seed` is entirely 0, it will be
+    /// mapped to a different seed.
+    #[inline]
+    fn from_seed(seed: [u8; 16]) -> Xoroshiro128Plus {
+        deal_with_zero_seed!(seed, Self, 16);
+        let mut state = [0; 2];
+        read_u64_into(&seed, &mut state);
+        Xoroshiro128Plus {
+            s0: state[0],
+            s1: state[1],
+        }
+    }
+
+    /// Seed a `Xoroshiro128Plus` from a `u64` using `SplitMix64`.
+    fn seed_from_u64(seed: u64) -> Xoroshiro128Plus {
+        from_splitmix!(seed)
+    }
+}
+
+impl RngCore for Xoroshiro128Plus {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let result_plus = self.s0.wrapping_add(self.s1);
+        impl_xoroshiro_u64!(self);
+        result_plus
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_via_next(self, dest);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        let mut rng = Xoroshiro128Plus::from_seed([1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoroshiro128plus.c
+        let expected = [
+            3,
+            412333834243,
+            2360170716294286339,
+            9295852285959843169,
+            2797080929874688578,
+            6019711933173041966,
+            3076529664176959358,
+            3521761819100106140,
+            7493067640054542992,
+            920801338098114767,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn test_jump() {
+        let mut rng = Xoroshiro128Plus::from_seed([1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        rng.jump();
+        assert_eq!(rng.s0, 7420758724034209717);
+        assert_eq!(rng.s1, 9442990532527272306);
+    }
+
+    #[test]
+    fn test_long_jump() {
+        let mut rng = Xoroshiro128Plus::from_seed([1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        rng.long_jump();
+        assert_eq!(rng.s0, 4387707342976528954);
+        assert_eq!(rng.s1, 3072119776036644419);
+    }
+    #[test]
+    fn test_split_streams() {
+        let rng = Xoroshiro128Plus::seed_from_u64(0);
+        let mut streams = rng.clone().split_streams(3);
+        assert_eq!(streams.next().unwrap(), rng);
+        let mut expected = rng.clone();
+        expected.jump();
+        assert_eq!(streams.next().unwrap(), expected);
+        expected.jump();
+        assert_eq!(streams.next().unwrap(), expected);
+        assert!(streams.next().is_none());
+
+        let mut long_streams = rng.clone().split_streams_long(2);
+        assert_eq!(long_streams.next().unwrap(), rng);
+        let mut expected_long = rng;
+        expected_long.long_jump();
+        assert_eq!(long_streams.next().unwrap(), expected_long);
+        assert!(long_streams.next().is_none());
+    }
+}
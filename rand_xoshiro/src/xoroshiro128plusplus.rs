@@ -0,0 +1,168 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use rand_core::impls::fill_bytes_via_next;
+use rand_core::le::read_u64_into;
+use rand_core::{RngCore, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A xoroshiro128++ random number generator.
+///
+/// The xoroshiro128++ algorithm is not suitable for cryptographic purposes,
+/// but is very fast and has excellent statistical properties.
+///
+/// The algorithm used here is translated from [the `xoroshiro128plusplus.c`
+/// reference source code](http://xoshiro.di.unimi.it/xoroshiro128plusplus.c)
+/// by David Blackman and Sebastiano Vigna.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Xoroshiro128PlusPlus {
+    s0: u64,
+    s1: u64,
+}
+
+impl Xoroshiro128PlusPlus {
+    /// Jump forward, equivalently to 2^64 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^64 non-overlapping subsequences for
+    /// parallel computations.
+    ///
+    /// ```
+    /// use rand_xoshiro::rand_core::SeedableRng;
+    /// use rand_xoshiro::Xoroshiro128PlusPlus;
+    ///
+    /// let rng1 = Xoroshiro128PlusPlus::seed_from_u64(0);
+    /// let mut rng2 = rng1.clone();
+    /// rng2.jump();
+    /// let mut rng3 = rng2.clone();
+    /// rng3.jump();
+    /// ```
+    pub fn jump(&mut self) {
+        impl_jump_xoroshiro128!(self, [0x2bd7_a6a6_e99c_2ddc, 0x0992_ccaf_6a6f_ca05]);
+    }
+
+    /// Jump forward, equivalently to 2^96 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^32 starting points, from each of which
+    /// `jump()` will generate 2^32 non-overlapping subsequences for parallel
+    /// distributed computations.
+    pub fn long_jump(&mut self) {
+        impl_jump_xoroshiro128!(self, [0x360f_d5f2_cf8d_5d99, 0x9c6e_6877_736c_46e3]);
+    }
+
+    impl_split_streams!(Xoroshiro128PlusPlus);
+}
+
+impl SeedableRng for Xoroshiro128PlusPlus {
+    type Seed = [u8; 16];
+
+    /// Create a new `Xoroshiro128PlusPlus`.  If `seed` is entirely 0, it
+    /// will be mapped to a different seed.
+    #[inline]
+    fn from_seed(seed: [u8; 16]) -> Xoroshiro128PlusPlus {
+        deal_with_zero_seed!(seed, Self, 16);
+        let mut state = [0; 2];
+        read_u64_into(&seed, &mut state);
+        Xoroshiro128PlusPlus {
+            s0: state[0],
+            s1: state[1],
+        }
+    }
+
+    /// Seed a `Xoroshiro128PlusPlus` from a `u64` using `SplitMix64`.
+    fn seed_from_u64(seed: u64) -> Xoroshiro128PlusPlus {
+        from_splitmix!(seed)
+    }
+}
+
+impl RngCore for Xoroshiro128PlusPlus {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let result_plusplus = (self.s0.wrapping_add(self.s1))
+            .rotate_left(17)
+            .wrapping_add(self.s0);
+        impl_xoroshiro_u64_plusplus!(self);
+        result_plusplus
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_via_next(self, dest);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        let mut rng =
+            Xoroshiro128PlusPlus::from_seed([1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoroshiro128plusplus.c
+        let expected = [
+            393217,
+            669327710093319,
+            1732421326133921491,
+            11394790081659126983,
+            9555452776773192676,
+            3586421180005889563,
+            1691397964866707553,
+            10735626796753111697,
+            15216282715349408991,
+            14247243556711267923,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn test_jump() {
+        let mut rng =
+            Xoroshiro128PlusPlus::from_seed([1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        rng.jump();
+        assert_eq!(rng.s0, 8625214420338730171);
+        assert_eq!(rng.s1, 17730401117375794498);
+    }
+
+    #[test]
+    fn test_long_jump() {
+        let mut rng =
+            Xoroshiro128PlusPlus::from_seed([1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        rng.long_jump();
+        assert_eq!(rng.s0, 2219032219420146153);
+        assert_eq!(rng.s1, 9655216301158529667);
+    }
+    #[test]
+    fn test_split_streams() {
+        let rng = Xoroshiro128PlusPlus::seed_from_u64(0);
+        let mut streams = rng.clone().split_streams(3);
+        assert_eq!(streams.next().unwrap(), rng);
+        let mut expected = rng.clone();
+        expected.jump();
+        assert_eq!(streams.next().unwrap(), expected);
+        expected.jump();
+        assert_eq!(streams.next().unwrap(), expected);
+        assert!(streams.next().is_none());
+
+        let mut long_streams = rng.clone().split_streams_long(2);
+        assert_eq!(long_streams.next().unwrap(), rng);
+        let mut expected_long = rng;
+        expected_long.long_jump();
+        assert_eq!(long_streams.next().unwrap(), expected_long);
+        assert!(long_streams.next().is_none());
+    }
+}
@@ -0,0 +1,166 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use rand_core::impls::fill_bytes_via_next;
+use rand_core::le::read_u64_into;
+use rand_core::{RngCore, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A xoroshiro128** random number generator.
+///
+/// The xoroshiro128** algorithm is not suitable for cryptographic purposes,
+/// but is very fast and has excellent statistical properties.
+///
+/// The algorithm used here is translated from [the `xoroshiro128starstar.c`
+/// reference source code](http://xoshiro.di.unimi.it/xoroshiro128starstar.c)
+/// by David Blackman and Sebastiano Vigna.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Xoroshiro128StarStar {
+    s0: u64,
+    s1: u64,
+}
+
+impl Xoroshiro128StarStar {
+    /// Jump forward, equivalently to 2^64 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^64 non-overlapping subsequences for
+    /// parallel computations.
+    ///
+    /// ```
+    /// use rand_xoshiro::rand_core::SeedableRng;
+    /// use rand_xoshiro::Xoroshiro128StarStar;
+    ///
+    /// let rng1 = Xoroshiro128StarStar::seed_from_u64(0);
+    /// let mut rng2 = rng1.clone();
+    /// rng2.jump();
+    /// let mut rng3 = rng2.clone();
+    /// rng3.jump();
+    /// ```
+    pub fn jump(&mut self) {
+        impl_jump_xoroshiro128!(self, [0xdf90_0294_d8f5_54a5, 0x1708_65df_4b32_01fc]);
+    }
+
+    /// Jump forward, equivalently to 2^96 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^32 starting points, from each of which
+    /// `jump()` will generate 2^32 non-overlapping subsequences for parallel
+    /// distributed computations.
+    pub fn long_jump(&mut self) {
+        impl_jump_xoroshiro128!(self, [0xd2a9_8b26_625e_ee7b, 0xdddf_9b10_90aa_7ac1]);
+    }
+
+    impl_split_streams!(Xoroshiro128StarStar);
+}
+
+impl SeedableRng for Xoroshiro128StarStar {
+    type Seed = [u8; 16];
+
+    /// Create a new `Xoroshiro128StarStar`.  If `seed` is entirely 0, it
+    /// will be mapped to a different seed.
+    #[inline]
+    fn from_seed(seed: [u8; 16]) -> Xoroshiro128StarStar {
+        deal_with_zero_seed!(seed, Self, 16);
+        let mut state = [0; 2];
+        read_u64_into(&seed, &mut state);
+        Xoroshiro128StarStar {
+            s0: state[0],
+            s1: state[1],
+        }
+    }
+
+    /// Seed a `Xoroshiro128StarStar` from a `u64` using `SplitMix64`.
+    fn seed_from_u64(seed: u64) -> Xoroshiro128StarStar {
+        from_splitmix!(seed)
+    }
+}
+
+impl RngCore for Xoroshiro128StarStar {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let result_starstar = starstar_u64!(self.s0);
+        impl_xoroshiro_u64!(self);
+        result_starstar
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_via_next(self, dest);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        let mut rng =
+            Xoroshiro128StarStar::from_seed([1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoroshiro128starstar.c
+        let expected = [
+            5760,
+            97769243520,
+            9706862127477703552,
+            9223447511460779954,
+            8358291023205304566,
+            15695619998649302768,
+            8517900938696309774,
+            16586480348202605369,
+            6959129367028440372,
+            16822147227405758281,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn test_jump() {
+        let mut rng =
+            Xoroshiro128StarStar::from_seed([1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        rng.jump();
+        assert_eq!(rng.s0, 7420758724034209717);
+        assert_eq!(rng.s1, 9442990532527272306);
+    }
+
+    #[test]
+    fn test_long_jump() {
+        let mut rng =
+            Xoroshiro128StarStar::from_seed([1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        rng.long_jump();
+        assert_eq!(rng.s0, 4387707342976528954);
+        assert_eq!(rng.s1, 3072119776036644419);
+    }
+    #[test]
+    fn test_split_streams() {
+        let rng = Xoroshiro128StarStar::seed_from_u64(0);
+        let mut streams = rng.clone().split_streams(3);
+        assert_eq!(streams.next().unwrap(), rng);
+        let mut expected = rng.clone();
+        expected.jump();
+        assert_eq!(streams.next().unwrap(), expected);
+        expected.jump();
+        assert_eq!(streams.next().unwrap(), expected);
+        assert!(streams.next().is_none());
+
+        let mut long_streams = rng.clone().split_streams_long(2);
+        assert_eq!(long_streams.next().unwrap(), rng);
+        let mut expected_long = rng;
+        expected_long.long_jump();
+        assert_eq!(long_streams.next().unwrap(), expected_long);
+        assert!(long_streams.next().is_none());
+    }
+}
@@ -55,6 +55,8 @@ impl Xoshiro128Plus {
     pub fn long_jump(&mut self) {
         impl_jump!(u32, self, [0xb523952e, 0x0b6f099f, 0xccf5a0ef, 0x1c580662]);
     }
+
+    impl_split_streams!(Xoshiro128Plus);
 }
 
 impl SeedableRng for Xoshiro128Plus {
@@ -136,4 +138,24 @@ mod tests {
         assert_eq!(rng.s[2], 966769569);
         assert_eq!(rng.s[3], 3193880526);
     }
+
+    #[test]
+    fn test_split_streams() {
+        let rng = Xoshiro128Plus::seed_from_u64(0);
+        let mut streams = rng.clone().split_streams(3);
+        assert_eq!(streams.next().unwrap(), rng);
+        let mut expected = rng.clone();
+        expected.jump();
+        assert_eq!(streams.next().unwrap(), expected);
+        expected.jump();
+        assert_eq!(streams.next().unwrap(), expected);
+        assert!(streams.next().is_none());
+
+        let mut long_streams = rng.clone().split_streams_long(2);
+        assert_eq!(long_streams.next().unwrap(), rng);
+        let mut expected_long = rng;
+        expected_long.long_jump();
+        assert_eq!(long_streams.next().unwrap(), expected_long);
+        assert!(long_streams.next().is_none());
+    }
 }
@@ -0,0 +1,192 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use rand_core::impls::fill_bytes_via_next;
+use rand_core::le::read_u64_into;
+use rand_core::{RngCore, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A xoshiro256** random number generator.
+///
+/// The xoshiro256** algorithm is not suitable for cryptographic purposes, but
+/// is very fast and has excellent statistical properties. It is the
+/// workhorse 64-bit generator recommended by its authors for general-purpose
+/// use.
+///
+/// The algorithm used here is translated from [the `xoshiro256starstar.c`
+/// reference source code](http://xoshiro.di.unimi.it/xoshiro256starstar.c) by
+/// David Blackman and Sebastiano Vigna.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Xoshiro256StarStar {
+    s: [u64; 4],
+}
+
+impl Xoshiro256StarStar {
+    /// Jump forward, equivalently to 2^128 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^128 non-overlapping subsequences for
+    /// parallel computations.
+    ///
+    /// ```
+    /// use rand_xoshiro::rand_core::SeedableRng;
+    /// use rand_xoshiro::Xoshiro256StarStar;
+    ///
+    /// let rng1 = Xoshiro256StarStar::seed_from_u64(0);
+    /// let mut rng2 = rng1.clone();
+    /// rng2.jump();
+    /// let mut rng3 = rng2.clone();
+    /// rng3.jump();
+    /// ```
+    pub fn jump(&mut self) {
+        impl_jump!(
+            u64,
+            self,
+            [
+                0x180ec6d33cfd0aba,
+                0xd5a61266f0c9392c,
+                0xa9582618e03fc9aa,
+                0x39abdc4529b1661c
+            ]
+        );
+    }
+
+    /// Jump forward, equivalently to 2^192 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^64 starting points, from each of which
+    /// `jump()` will generate 2^64 non-overlapping subsequences for parallel
+    /// distributed computations.
+    pub fn long_jump(&mut self) {
+        impl_jump!(
+            u64,
+            self,
+            [
+                0x76e15d3efefdcbbf,
+                0xc5004e441c522fb3,
+                0x77710069854ee241,
+                0x39109bb02acbe635
+            ]
+        );
+    }
+
+    impl_split_streams!(Xoshiro256StarStar);
+}
+
+impl SeedableRng for Xoshiro256StarStar {
+    type Seed = [u8; 32];
+
+    /// Create a new `Xoshiro256StarStar`.  If `seed` is entirely 0, it will
+    /// be mapped to a different seed.
+    #[inline]
+    fn from_seed(seed: [u8; 32]) -> Xoshiro256StarStar {
+        deal_with_zero_seed!(seed, Self, 32);
+        let mut state = [0; 4];
+        read_u64_into(&seed, &mut state);
+        Xoshiro256StarStar { s: state }
+    }
+
+    /// Seed a `Xoshiro256StarStar` from a `u64` using `SplitMix64`.
+    fn seed_from_u64(seed: u64) -> Xoshiro256StarStar {
+        from_splitmix!(seed)
+    }
+}
+
+impl RngCore for Xoshiro256StarStar {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let result_starstar = starstar_u64!(self.s[1]);
+        impl_xoshiro_u64!(self);
+        result_starstar
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_via_next(self, dest);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        let mut rng = Xoshiro256StarStar::from_seed([
+            1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
+            0, 0, 0,
+        ]);
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro256starstar.c
+        let expected = [
+            11520,
+            0,
+            1509978240,
+            1215971899390074240,
+            1216172134540287360,
+            607988272756665600,
+            16172922978634559625,
+            8476171486693032832,
+            10595114339597558777,
+            2904607092377533576,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn test_jump() {
+        let mut rng = Xoshiro256StarStar::from_seed([
+            1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
+            0, 0, 0,
+        ]);
+        rng.jump();
+        assert_eq!(rng.s[0], 10122426448480695249);
+        assert_eq!(rng.s[1], 8079205330032121950);
+        assert_eq!(rng.s[2], 7289065458748526725);
+        assert_eq!(rng.s[3], 9477464255293849680);
+    }
+
+    #[test]
+    fn test_long_jump() {
+        let mut rng = Xoshiro256StarStar::from_seed([
+            1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
+            0, 0, 0,
+        ]);
+        rng.long_jump();
+        assert_eq!(rng.s[0], 678511610814637056);
+        assert_eq!(rng.s[1], 15850499779492529430);
+        assert_eq!(rng.s[2], 6002989639035333134);
+        assert_eq!(rng.s[3], 3559352929785830385);
+    }
+    #[test]
+    fn test_split_streams() {
+        let rng = Xoshiro256StarStar::seed_from_u64(0);
+        let mut streams = rng.clone().split_streams(3);
+        assert_eq!(streams.next().unwrap(), rng);
+        let mut expected = rng.clone();
+        expected.jump();
+        assert_eq!(streams.next().unwrap(), expected);
+        expected.jump();
+        assert_eq!(streams.next().unwrap(), expected);
+        assert!(streams.next().is_none());
+
+        let mut long_streams = rng.clone().split_streams_long(2);
+        assert_eq!(long_streams.next().unwrap(), rng);
+        let mut expected_long = rng;
+        expected_long.long_jump();
+        assert_eq!(long_streams.next().unwrap(), expected_long);
+        assert!(long_streams.next().is_none());
+    }
+}